@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use miette::miette;
+
 use crate::appearance::{BackgroundEffectRule, BlockOutFrom, CornerRadius, ShadowRule};
 use crate::utils::RegexEq;
 
@@ -32,4 +36,31 @@ pub struct Match {
     pub at_startup: Option<bool>,
     #[knuffel(property, str)]
     pub layer: Option<niri_ipc::Layer>,
+    #[knuffel(property, str)]
+    pub anchor: Option<LayerAnchor>,
+}
+
+/// Edge of the output that a layer-shell surface is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerAnchor {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl FromStr for LayerAnchor {
+    type Err = miette::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "top" => Ok(Self::Top),
+            "bottom" => Ok(Self::Bottom),
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            _ => Err(miette!(
+                r#"invalid anchor, can be "top", "bottom", "left" or "right""#
+            )),
+        }
+    }
 }
@@ -862,7 +862,7 @@ mod tests {
             }
 
             window-rule {
-                match app-id=".*alacritty"
+                match app-id=".*alacritty" max-width=1200 aspect-ratio=1.78 aspect-ratio-tolerance=0.1
                 exclude title="~"
                 exclude is-active=true is-focused=false
 
@@ -891,7 +891,7 @@ mod tests {
             }
 
             layer-rule {
-                match namespace="^notifications$"
+                match namespace="^notifications$" anchor="bottom"
                 block-out-from "screencast"
             }
 
@@ -1778,6 +1778,22 @@ mod tests {
                             is_window_cast_target: None,
                             is_urgent: None,
                             at_startup: None,
+                            min_width: None,
+                            max_width: Some(
+                                1200,
+                            ),
+                            min_height: None,
+                            max_height: None,
+                            aspect_ratio: Some(
+                                FloatOrInt(
+                                    1.78,
+                                ),
+                            ),
+                            aspect_ratio_tolerance: Some(
+                                FloatOrInt(
+                                    0.1,
+                                ),
+                            ),
                         },
                     ],
                     excludes: [
@@ -1797,6 +1813,12 @@ mod tests {
                             is_window_cast_target: None,
                             is_urgent: None,
                             at_startup: None,
+                            min_width: None,
+                            max_width: None,
+                            min_height: None,
+                            max_height: None,
+                            aspect_ratio: None,
+                            aspect_ratio_tolerance: None,
                         },
                         Match {
                             app_id: None,
@@ -1812,6 +1834,12 @@ mod tests {
                             is_window_cast_target: None,
                             is_urgent: None,
                             at_startup: None,
+                            min_width: None,
+                            max_width: None,
+                            min_height: None,
+                            max_height: None,
+                            aspect_ratio: None,
+                            aspect_ratio_tolerance: None,
                         },
                     ],
                     default_column_width: None,
@@ -1946,6 +1974,9 @@ mod tests {
                             ),
                             at_startup: None,
                             layer: None,
+                            anchor: Some(
+                                Bottom,
+                            ),
                         },
                     ],
                     excludes: [],
@@ -98,6 +98,18 @@ pub struct Match {
     pub is_urgent: Option<bool>,
     #[knuffel(property)]
     pub at_startup: Option<bool>,
+    #[knuffel(property)]
+    pub min_width: Option<u16>,
+    #[knuffel(property)]
+    pub max_width: Option<u16>,
+    #[knuffel(property)]
+    pub min_height: Option<u16>,
+    #[knuffel(property)]
+    pub max_height: Option<u16>,
+    #[knuffel(property)]
+    pub aspect_ratio: Option<FloatOrInt<0, 65535>>,
+    #[knuffel(property)]
+    pub aspect_ratio_tolerance: Option<FloatOrInt<0, 65535>>,
 }
 
 #[derive(knuffel::Decode, Debug, Clone, Copy, PartialEq)]
@@ -1,8 +1,10 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Read;
+use std::iter;
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use anyhow::{anyhow, Context};
@@ -31,10 +33,11 @@ impl CursorManager {
     pub fn new(theme: &str, size: u8) -> Self {
         Self::ensure_env(theme, size);
 
-        let theme = CursorTheme::load(theme);
+        let xcursor_theme = CursorTheme::load(theme);
+        Self::check_theme(&xcursor_theme, theme, size);
 
         Self {
-            theme,
+            theme: xcursor_theme,
             size,
             current_cursor: CursorImageStatus::default_named(),
             named_cursor_cache: Default::default(),
@@ -45,6 +48,7 @@ impl CursorManager {
     pub fn reload(&mut self, theme: &str, size: u8) {
         Self::ensure_env(theme, size);
         self.theme = CursorTheme::load(theme);
+        Self::check_theme(&self.theme, theme, size);
         self.size = size;
         self.named_cursor_cache.get_mut().clear();
     }
@@ -192,6 +196,19 @@ impl CursorManager {
         env::set_var("XCURSOR_SIZE", size.to_string());
     }
 
+    /// Warn if the theme can't provide cursors of this size, since we silently fall back otherwise.
+    fn check_theme(theme: &CursorTheme, name: &str, size: u8) {
+        // "default" is the conventional name for the system-wide theme, and it's fine for it to be
+        // missing.
+        if name == "default" {
+            return;
+        }
+
+        if let Some(warning) = theme_warning(theme, name, size, &theme_search_paths()) {
+            warn!("{warning}");
+        }
+    }
+
     fn fallback_cursor() -> XCursor {
         let images = vec![Image {
             size: 32,
@@ -211,6 +228,86 @@ impl CursorManager {
     }
 }
 
+/// Checks that the theme has the default cursor in the given size.
+///
+/// This uses the same lookup as loading the cursors, so inherited themes are handled, and icon
+/// themes without cursors are reported as missing. `search_paths` are only used to list the
+/// available themes in the warning.
+fn theme_warning(
+    theme: &CursorTheme,
+    name: &str,
+    size: u8,
+    search_paths: &[PathBuf],
+) -> Option<String> {
+    let icon = CursorIcon::Default;
+    let path = iter::once(icon.name())
+        .chain(icon.alt_names().iter().copied())
+        .find_map(|icon_name| theme.load_icon(icon_name));
+
+    let Some(path) = path else {
+        let themes = available_themes(search_paths);
+        let warning = if themes.is_empty() {
+            format!("xcursor theme {name:?} not found, and no cursor themes are installed")
+        } else {
+            format!(
+                "xcursor theme {name:?} not found or has no cursors, available themes: {}",
+                themes.join(", ")
+            )
+        };
+        return Some(warning);
+    };
+
+    // Errors reading the file are reported when loading the cursor.
+    let images = parse_xcursor(&fs::read(path).ok()?)?;
+    let mut sizes: Vec<u32> = images.iter().map(|image| image.size).collect();
+    sizes.sort_unstable();
+    sizes.dedup();
+
+    if sizes.contains(&u32::from(size)) {
+        return None;
+    }
+
+    let sizes: Vec<String> = sizes.iter().map(|size| size.to_string()).collect();
+    Some(format!(
+        "xcursor theme {name:?} has no size {size} cursors, using the closest of: {}",
+        sizes.join(", ")
+    ))
+}
+
+/// Directories to list cursor themes from when suggesting alternatives.
+///
+/// [`CursorTheme`] does the actual lookup. This is only for the warning, so it covers just
+/// `XCURSOR_PATH` and the common entries of the libXcursor default `XCURSORPATH`.
+fn theme_search_paths() -> Vec<PathBuf> {
+    if let Some(paths) = env::var_os("XCURSOR_PATH") {
+        return env::split_paths(&paths).collect();
+    }
+
+    let mut paths = Vec::new();
+    if let Some(home) = env::var_os("HOME").map(PathBuf::from) {
+        paths.push(home.join(".local/share/icons"));
+        paths.push(home.join(".icons"));
+    }
+    paths.push(PathBuf::from("/usr/share/icons"));
+    paths.push(PathBuf::from("/usr/share/pixmaps"));
+    paths
+}
+
+/// Sorted names of all themes with cursors in the search paths.
+fn available_themes(search_paths: &[PathBuf]) -> Vec<String> {
+    let mut themes: Vec<String> = search_paths
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("cursors").is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    themes.sort_unstable();
+    themes.dedup();
+    themes
+}
+
 /// The cursor prepared for renderer.
 pub enum RenderCursor {
     Hidden,
@@ -316,3 +413,96 @@ impl XCursor {
         (image.xhot as i32, image.yhot as i32).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use xshell::Shell;
+
+    use super::*;
+
+    /// Builds an Xcursor file with a 1×1 image for each of the nominal sizes.
+    fn xcursor_file(sizes: &[u32]) -> Vec<u8> {
+        const IMAGE_TYPE: u32 = 0xfffd0002;
+        const IMAGE_HEADER_LEN: u32 = 36;
+
+        let toc_end = 16 + 12 * sizes.len() as u32;
+        let image_len = IMAGE_HEADER_LEN + 4;
+
+        // Header length, version, number of TOC entries.
+        let mut words = vec![16, 0x1_0000, sizes.len() as u32];
+        for (i, &size) in sizes.iter().enumerate() {
+            words.extend([IMAGE_TYPE, size, toc_end + image_len * i as u32]);
+        }
+        for &size in sizes {
+            // Header length, type, nominal size, version.
+            words.extend([IMAGE_HEADER_LEN, IMAGE_TYPE, size, 1]);
+            // Width, height, xhot, yhot, delay.
+            words.extend([1, 1, 0, 0, 0]);
+            // A single white pixel.
+            words.push(0xffffffff);
+        }
+
+        let mut buf = b"Xcur".to_vec();
+        buf.extend(words.iter().flat_map(|word| word.to_le_bytes()));
+        buf
+    }
+
+    /// Loads a theme from `dir` only.
+    ///
+    /// [`CursorTheme`] reads the search path from `XCURSOR_PATH` when loading, so set it just for
+    /// the load and restore it right after.
+    fn load_theme(dir: &Path, name: &str) -> CursorTheme {
+        let old = env::var_os("XCURSOR_PATH");
+        env::set_var("XCURSOR_PATH", dir);
+        let theme = CursorTheme::load(name);
+        match old {
+            Some(old) => env::set_var("XCURSOR_PATH", old),
+            None => env::remove_var("XCURSOR_PATH"),
+        }
+        theme
+    }
+
+    #[test]
+    fn theme_warnings() {
+        let sh = Shell::new().unwrap();
+        let temp_dir = sh.create_temp_dir().unwrap();
+        let dir = temp_dir.path();
+
+        sh.write_file(dir.join("Adwaita/cursors/default"), xcursor_file(&[24, 48]))
+            .unwrap();
+        // Inherit-only themes don't have cursors, but can still be used.
+        sh.write_file(
+            dir.join("inherit-only/index.theme"),
+            "[Icon Theme]\nInherits=Adwaita\n",
+        )
+        .unwrap();
+        // Icon themes without cursors aren't cursor themes.
+        sh.write_file(dir.join("hicolor/index.theme"), "[Icon Theme]\n")
+            .unwrap();
+
+        let search_paths = [dir.to_owned()];
+        let warning = |name: &str, size| {
+            let theme = load_theme(dir, name);
+            theme_warning(&theme, name, size, &search_paths)
+        };
+
+        assert_eq!(warning("Adwaita", 24), None);
+        assert_eq!(warning("inherit-only", 48), None);
+
+        assert_snapshot!(
+            warning("Adwaita", 32).unwrap(),
+            @r#"xcursor theme "Adwaita" has no size 32 cursors, using the closest of: 24, 48"#
+        );
+        assert_snapshot!(
+            warning("hicolor", 24).unwrap(),
+            @r#"xcursor theme "hicolor" not found or has no cursors, available themes: Adwaita"#
+        );
+        assert_snapshot!(
+            warning("Adwiata", 24).unwrap(),
+            @r#"xcursor theme "Adwiata" not found or has no cursors, available themes: Adwaita"#
+        );
+    }
+}
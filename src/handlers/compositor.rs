@@ -24,7 +24,7 @@ use crate::layout::{ActivateWindow, AddWindowTarget, LayoutElement as _};
 use crate::niri::{CastTarget, ClientState, LockState, State};
 use crate::utils::transaction::Transaction;
 use crate::utils::{is_mapped, send_scale_transform};
-use crate::window::{InitialConfigureState, Mapped, ResolvedWindowRules, Unmapped};
+use crate::window::{InitialConfigureState, Mapped, ResolvedWindowRules, Unmapped, WindowRef};
 
 impl CompositorHandler for State {
     fn compositor_state(&mut self) -> &mut CompositorState {
@@ -78,17 +78,31 @@ impl CompositorHandler for State {
 
         if surface == &root_surface {
             // This is a root surface commit. It might have mapped a previously-unmapped toplevel.
-            if let Entry::Occupied(entry) = self.niri.unmapped_windows.entry(surface.clone()) {
+            if let Entry::Occupied(mut entry) = self.niri.unmapped_windows.entry(surface.clone()) {
                 if is_mapped(surface) {
                     // The toplevel got mapped.
+                    let unmapped = entry.get_mut();
+                    unmapped.window.on_commit();
+
+                    // Size matchers only start matching at the first commit, so recompute the
+                    // rules now that we know the window size.
+                    unmapped.size_at_map = Some(unmapped.window.geometry().size);
+                    let new_rules = ResolvedWindowRules::compute(
+                        &self.niri.config.borrow().window_rules,
+                        WindowRef::Unmapped(unmapped),
+                        self.niri.is_at_startup,
+                    );
+                    if let InitialConfigureState::Configured { rules, .. } = &mut unmapped.state {
+                        *rules = new_rules;
+                    }
+
                     let Unmapped {
                         window,
                         state,
                         activation_token_data,
+                        size_at_map: _,
                     } = entry.remove();
 
-                    window.on_commit();
-
                     let toplevel = window.toplevel().expect("no X11 support");
 
                     let (
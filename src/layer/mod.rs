@@ -1,8 +1,8 @@
-use niri_config::layer_rule::{LayerRule, Match};
+use niri_config::layer_rule::{LayerAnchor, LayerRule, Match};
 use niri_config::utils::MergeWith as _;
 use niri_config::{BackgroundEffect, BlockOutFrom, CornerRadius, ShadowRule};
 use smithay::desktop::LayerSurface;
-use smithay::wayland::shell::wlr_layer::Layer;
+use smithay::wayland::shell::wlr_layer::{Anchor, Layer};
 
 pub mod mapped;
 
@@ -105,5 +105,17 @@ fn surface_matches(surface: &LayerSurface, m: &Match) -> bool {
         }
     }
 
+    if let Some(anchor) = m.anchor {
+        let edge = match anchor {
+            LayerAnchor::Top => Anchor::TOP,
+            LayerAnchor::Bottom => Anchor::BOTTOM,
+            LayerAnchor::Left => Anchor::LEFT,
+            LayerAnchor::Right => Anchor::RIGHT,
+        };
+        if !surface.cached_state().anchor.contains(edge) {
+            return false;
+        }
+    }
+
     true
 }
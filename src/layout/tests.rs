@@ -3409,6 +3409,45 @@ fn preset_column_width_reset_after_set_width() {
     assert_eq!(win.requested_size().unwrap().w, 500);
 }
 
+#[test]
+fn two_half_proportion_columns_fill_working_area() {
+    let ops = [
+        Op::AddOutput(0),
+        Op::AddWindow {
+            params: TestWindowParams::new(0),
+        },
+        Op::SetColumnWidth(SizeChange::SetProportion(50.)),
+        Op::AddWindow {
+            params: TestWindowParams::new(1),
+        },
+        Op::SetColumnWidth(SizeChange::SetProportion(50.)),
+    ];
+
+    let options = Options {
+        layout: niri_config::Layout {
+            gaps: 16.,
+            struts: Struts {
+                left: FloatOrInt(50.),
+                right: FloatOrInt(30.),
+                top: FloatOrInt(0.),
+                bottom: FloatOrInt(0.),
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let layout = check_ops_with_options(options, ops);
+
+    // The output is 1280 wide, so the working area is 1280 - 50 - 30 = 1200 wide. Proportions
+    // are of the working area, with a gap on either side of and between the columns.
+    let widths: Vec<i32> = layout
+        .windows()
+        .map(|(_, win)| win.requested_size().unwrap().w)
+        .collect();
+    assert_eq!(widths, [576, 576]);
+    assert_eq!(16 + widths[0] + 16 + widths[1] + 16, 1200);
+}
+
 #[test]
 fn move_column_to_workspace_unfocused_with_multiple_monitors() {
     let ops = [
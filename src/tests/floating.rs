@@ -1364,3 +1364,76 @@ fn repeated_size_request() {
         @""
     );
 }
+
+// Opens a window that commits the given size and returns whether it opened as floating.
+fn opens_floating_with_size(config: &str, w: u16, h: u16) -> bool {
+    let config = Config::parse_mem(config).unwrap();
+    let mut f = Fixture::with_config(config);
+    f.add_output(1, (1920, 1080));
+
+    let id = f.add_client();
+    let window = f.client(id).create_window();
+    let surface = window.surface.clone();
+    window.commit();
+    f.roundtrip(id);
+
+    let window = f.client(id).window(&surface);
+    window.attach_new_buffer();
+    window.set_size(w, h);
+    window.ack_last_and_commit();
+    f.double_roundtrip(id);
+
+    let mapped = f.niri().layout.windows().next().unwrap().1;
+    mapped.is_floating()
+}
+
+#[test]
+fn open_floating_by_size_matcher() {
+    let config = r##"
+window-rule {
+    match max-width=400 max-height=300
+    open-floating true
+}
+"##;
+
+    assert!(opens_floating_with_size(config, 300, 200));
+    assert!(opens_floating_with_size(config, 400, 300));
+    assert!(!opens_floating_with_size(config, 401, 300));
+    assert!(!opens_floating_with_size(config, 300, 800));
+
+    let config = r##"
+window-rule {
+    match min-width=1000
+    open-floating true
+}
+"##;
+
+    assert!(opens_floating_with_size(config, 1000, 100));
+    assert!(!opens_floating_with_size(config, 999, 100));
+}
+
+#[test]
+fn open_floating_by_aspect_ratio_matcher() {
+    let config = r##"
+window-rule {
+    match aspect-ratio=1.78 aspect-ratio-tolerance=0.1
+    open-floating true
+}
+"##;
+
+    assert!(opens_floating_with_size(config, 1600, 900));
+    assert!(opens_floating_with_size(config, 1700, 1000));
+    assert!(!opens_floating_with_size(config, 900, 900));
+    assert!(!opens_floating_with_size(config, 900, 1600));
+
+    // The default tolerance is 0.05.
+    let config = r##"
+window-rule {
+    match aspect-ratio=1.78
+    open-floating true
+}
+"##;
+
+    assert!(opens_floating_with_size(config, 1600, 900));
+    assert!(!opens_floating_with_size(config, 1700, 1000));
+}
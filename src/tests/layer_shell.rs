@@ -1,11 +1,12 @@
 use insta::assert_snapshot;
+use niri_config::Config;
 use smithay::reexports::wayland_protocols_wlr::layer_shell::v1::client::zwlr_layer_shell_v1::Layer;
 use smithay::reexports::wayland_protocols_wlr::layer_shell::v1::client::zwlr_layer_surface_v1::{
     Anchor, KeyboardInteractivity,
 };
 
 use super::*;
-use crate::tests::client::{LayerConfigureProps, LayerMargin};
+use crate::tests::client::{ClientId, LayerConfigureProps, LayerMargin};
 
 #[test]
 fn simple_top_anchor() {
@@ -256,3 +257,56 @@ fn multiple_commits_before_mapping() {
     // Configure with new size.
     assert_snapshot!(layer.format_recent_configures(), @"size: 1920 × 50");
 }
+
+fn map_layer(f: &mut Fixture, id: ClientId, namespace: &str, layer: Layer, anchor: Anchor) {
+    let layer = f.client(id).create_layer(None, layer, namespace);
+    let surface = layer.surface.clone();
+    layer.set_configure_props(LayerConfigureProps {
+        anchor: Some(anchor),
+        size: Some((0, 50)),
+        ..Default::default()
+    });
+    layer.commit();
+    f.roundtrip(id);
+
+    let layer = f.client(id).layer(&surface);
+    layer.attach_new_buffer();
+    layer.set_size(100, 100);
+    layer.ack_last_and_commit();
+    f.double_roundtrip(id);
+}
+
+#[test]
+fn layer_rule_matches_layer_and_anchor() {
+    let config = r##"
+layer-rule {
+    match layer="top" anchor="bottom"
+    opacity 0.5
+}
+"##;
+    let config = Config::parse_mem(config).unwrap();
+    let mut f = Fixture::with_config(config);
+    f.add_output(1, (1920, 1080));
+    let id = f.add_client();
+
+    let bottom = Anchor::Left | Anchor::Right | Anchor::Bottom;
+    let top = Anchor::Left | Anchor::Right | Anchor::Top;
+    map_layer(&mut f, id, "top-bottom", Layer::Top, bottom);
+    map_layer(&mut f, id, "bottom-bottom", Layer::Bottom, bottom);
+    map_layer(&mut f, id, "top-top", Layer::Top, top);
+
+    let niri = f.niri();
+    let opacity = |namespace: &str| {
+        niri.mapped_layer_surfaces
+            .values()
+            .find(|mapped| mapped.surface().namespace() == namespace)
+            .unwrap()
+            .rules()
+            .opacity
+    };
+
+    // Both the layer and the anchor must match.
+    assert_eq!(opacity("top-bottom"), Some(0.5));
+    assert_eq!(opacity("bottom-bottom"), None);
+    assert_eq!(opacity("top-top"), None);
+}
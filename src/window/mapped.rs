@@ -101,6 +101,12 @@ pub struct Mapped {
     /// Whether this window is a target of a window cast.
     is_window_cast_target: bool,
 
+    /// Size of the window at its first commit, used for the window rule size matchers.
+    ///
+    /// This is set once and never updated, so that rules like max-width cannot make the window
+    /// stop matching its own rule.
+    size_at_map: Size<i32, Logical>,
+
     /// Whether this window should ignore opacity set through window rules.
     ignore_opacity_window_rule: bool,
 
@@ -255,6 +261,7 @@ impl Mapped {
     pub fn new(window: Window, rules: ResolvedWindowRules, hook: HookId) -> Self {
         let surface = window.wl_surface().expect("no X11 support");
         let credentials = get_credentials_for_surface(&surface);
+        let size_at_map = window.geometry().size;
 
         let mut rv = Self {
             window,
@@ -271,6 +278,7 @@ impl Mapped {
             is_active_in_column: true,
             is_floating: false,
             is_window_cast_target: false,
+            size_at_map,
             ignore_opacity_window_rule: false,
             block_out_buffer: RefCell::new(SolidColorBuffer::new((0., 0.), [0., 0., 0., 1.])),
             animate_next_configure: false,
@@ -359,6 +367,10 @@ impl Mapped {
         self.is_floating
     }
 
+    pub fn size_at_map(&self) -> Size<i32, Logical> {
+        self.size_at_map
+    }
+
     pub fn is_window_cast_target(&self) -> bool {
         self.is_window_cast_target
     }
@@ -174,6 +174,16 @@ impl<'a> WindowRef<'a> {
             WindowRef::Mapped(mapped) => mapped.is_window_cast_target(),
         }
     }
+
+    pub fn size_at_map(self) -> Option<Size<i32, Logical>> {
+        match self {
+            // None during the initial configure, which means that size matchers cannot affect
+            // initial configure rules. The rules are recomputed with the size right before
+            // mapping.
+            WindowRef::Unmapped(unmapped) => unmapped.size_at_map,
+            WindowRef::Mapped(mapped) => Some(mapped.size_at_map()),
+        }
+    }
 }
 
 impl ResolvedWindowRules {
@@ -440,5 +450,36 @@ fn window_matches(window: WindowRef, role: &XdgToplevelSurfaceRoleAttributes, m:
         }
     }
 
+    let has_size_matchers = m.min_width.is_some()
+        || m.max_width.is_some()
+        || m.min_height.is_some()
+        || m.max_height.is_some()
+        || m.aspect_ratio.is_some();
+    if has_size_matchers {
+        let Some(size) = window.size_at_map() else {
+            return false;
+        };
+
+        if m.min_width.is_some_and(|x| size.w < i32::from(x))
+            || m.max_width.is_some_and(|x| size.w > i32::from(x))
+            || m.min_height.is_some_and(|x| size.h < i32::from(x))
+            || m.max_height.is_some_and(|x| size.h > i32::from(x))
+        {
+            return false;
+        }
+
+        if let Some(aspect_ratio) = m.aspect_ratio {
+            if size.w <= 0 || size.h <= 0 {
+                return false;
+            }
+
+            let ratio = f64::from(size.w) / f64::from(size.h);
+            let tolerance = m.aspect_ratio_tolerance.map_or(0.05, |x| x.0);
+            if (ratio - aspect_ratio.0).abs() > tolerance {
+                return false;
+            }
+        }
+    }
+
     true
 }
@@ -1,6 +1,7 @@
 use niri_config::PresetSize;
 use smithay::desktop::Window;
 use smithay::output::Output;
+use smithay::utils::{Logical, Size};
 use smithay::wayland::shell::xdg::ToplevelSurface;
 use smithay::wayland::xdg_activation::XdgActivationTokenData;
 
@@ -12,6 +13,10 @@ pub struct Unmapped {
     pub state: InitialConfigureState,
     /// Activation token, if one was used on this unmapped window.
     pub activation_token_data: Option<XdgActivationTokenData>,
+    /// Size of the window at its first commit.
+    ///
+    /// This is `None` until the window commits its first buffer and is about to be mapped.
+    pub size_at_map: Option<Size<i32, Logical>>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -87,6 +92,7 @@ impl Unmapped {
                 wants_maximized: false,
             },
             activation_token_data: None,
+            size_at_map: None,
         }
     }
 